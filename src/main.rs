@@ -1,7 +1,10 @@
 use anyhow::Result;
 use miniquad::*;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 const RENDERS: i8 = 3; // amount of times to render the screen
 
@@ -12,16 +15,220 @@ static SUPPORTED_IMAGE_TYPES: &'static [&'static str] = &[
     "jpg", "jpeg",
     "png",
     "bmp",
-    "tif"
+    "tif",
+    "gif",
+    "webp",
+    "svg",
+    "avif",
+    "heic"
 ];
 
-fn get_filelist() -> (Images, Option<usize>) {
+// Kind of archive an `ImageSource::Archive` was opened from.
+#[derive(Clone, Copy)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+}
+
+// Where the viewer pulls its images from: a scanned directory, or the entries
+// of a zip/cbz/tar archive read straight into memory without unpacking to disk.
+#[derive(Clone)]
+enum ImageSource {
+    Directory(Images),
+    Archive {
+        path: PathBuf,
+        kind: ArchiveKind,
+        entries: Vec<String>,
+    },
+}
+
+impl ImageSource {
+    fn len(&self) -> usize {
+        match self {
+            ImageSource::Directory(images) => images.len(),
+            ImageSource::Archive { entries, .. } => entries.len(),
+        }
+    }
+
+    // A path usable for extension-based format detection and HUD display.
+    fn path(&self, index: usize) -> PathBuf {
+        match self {
+            ImageSource::Directory(images) => images[index].clone(),
+            ImageSource::Archive { entries, .. } => PathBuf::from(&entries[index]),
+        }
+    }
+
+    // Read the raw bytes of the image at `index` from disk or the archive.
+    fn read(&self, index: usize) -> Result<Vec<u8>> {
+        match self {
+            ImageSource::Directory(images) => Ok(std::fs::read(&images[index])?),
+            ImageSource::Archive {
+                path,
+                kind,
+                entries,
+            } => {
+                let name = &entries[index];
+                let file = std::fs::File::open(path)?;
+                match kind {
+                    ArchiveKind::Zip => {
+                        let mut archive = zip::ZipArchive::new(file)?;
+                        let mut entry = archive.by_name(name)?;
+                        let mut bytes = Vec::with_capacity(entry.size() as usize);
+                        std::io::copy(&mut entry, &mut bytes)?;
+                        Ok(bytes)
+                    }
+                    ArchiveKind::Tar => {
+                        let mut archive = tar::Archive::new(file);
+                        for entry in archive.entries()? {
+                            let mut entry = entry?;
+                            let matches = entry
+                                .path()
+                                .map(|p| p.to_string_lossy() == name.as_str())
+                                .unwrap_or(false);
+                            if matches {
+                                let mut bytes = Vec::with_capacity(entry.size() as usize);
+                                std::io::copy(&mut entry, &mut bytes)?;
+                                return Ok(bytes);
+                            }
+                        }
+                        anyhow::bail!("archive entry not found: {name}")
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Result of a background decode, distinguishing a permanent skip (an animated
+// png we must not collapse) from a transient failure that's worth retrying.
+enum Decoded {
+    Image(image::RgbaImage),
+    Skip,
+    Failed,
+}
+
+// The decode worker's view of the image source. It reads bytes off the worker
+// thread (not just decodes) so navigation never blocks the main thread on I/O,
+// and keeps a zip archive open across requests so paging through a `.cbz`
+// doesn't reparse the central directory on every prefetch.
+struct PrefetchReader {
+    source: ImageSource,
+    zip: Option<zip::ZipArchive<std::fs::File>>,
+}
+
+impl PrefetchReader {
+    fn new(source: ImageSource) -> PrefetchReader {
+        PrefetchReader { source, zip: None }
+    }
+
+    fn read(&mut self, index: usize) -> Result<Vec<u8>> {
+        match &self.source {
+            ImageSource::Archive {
+                path,
+                kind: ArchiveKind::Zip,
+                entries,
+            } => {
+                let name = entries[index].clone();
+                if self.zip.is_none() {
+                    self.zip = Some(zip::ZipArchive::new(std::fs::File::open(path)?)?);
+                }
+                let archive = self.zip.as_mut().unwrap();
+                let mut entry = archive.by_name(&name)?;
+                let mut bytes = Vec::with_capacity(entry.size() as usize);
+                std::io::copy(&mut entry, &mut bytes)?;
+                Ok(bytes)
+            }
+            // Directory reads and sequential tar reads have nothing to cache.
+            _ => self.source.read(index),
+        }
+    }
+}
+
+// Decide whether a path should be treated as an archive from its extension.
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    match path.extension().and_then(OsStr::to_str) {
+        Some("zip" | "cbz") => Some(ArchiveKind::Zip),
+        Some("tar") => Some(ArchiveKind::Tar),
+        _ => None,
+    }
+}
+
+// Keep only the entry names whose extension is a supported image type.
+fn filter_archive_entries(names: impl Iterator<Item = String>) -> Vec<String> {
+    let supported = SUPPORTED_IMAGE_TYPES
+        .iter()
+        .map(|s| OsStr::new(s))
+        .collect::<Vec<&OsStr>>();
+
+    let mut entries = names
+        .filter(|name| {
+            Path::new(name)
+                .extension()
+                .map(|ext| supported.contains(&ext))
+                .unwrap_or(false)
+        })
+        .collect::<Vec<String>>();
+
+    // Modification dates are unavailable inside archives, so sort by name.
+    entries.sort();
+    entries
+}
+
+// Build an archive-backed image source from a zip/cbz/tar path.
+fn get_archive(path: &Path, kind: ArchiveKind) -> ImageSource {
+    let file = std::fs::File::open(path).expect("problem opening archive");
+    let entries = match kind {
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(file).expect("problem reading archive");
+            let names = (0..archive.len()).map(|i| {
+                archive
+                    .by_index(i)
+                    .expect("problem reading archive entry")
+                    .name()
+                    .to_string()
+            });
+            filter_archive_entries(names)
+        }
+        ArchiveKind::Tar => {
+            let mut archive = tar::Archive::new(file);
+            let names = archive
+                .entries()
+                .expect("problem reading archive")
+                .map(|e| {
+                    e.expect("problem reading archive entry")
+                        .path()
+                        .expect("invalid archive entry path")
+                        .to_string_lossy()
+                        .into_owned()
+                });
+            filter_archive_entries(names)
+        }
+    };
+
+    entries.iter().for_each(|e| {
+        println!("Found image: {:#?}", e);
+    });
+
+    ImageSource::Archive {
+        path: path.to_path_buf(),
+        kind,
+        entries,
+    }
+}
+
+fn get_filelist() -> (ImageSource, Option<usize>) {
     let supported_image_types = SUPPORTED_IMAGE_TYPES
         .iter()
         .map(|s| OsStr::new(s))
         .collect::<Vec<&OsStr>>();
     let file = std::env::args().last().expect("no file specified");
     let file_path = Path::new(&file);
+
+    // An archive path pages through its entries instead of a directory scan.
+    if let Some(kind) = archive_kind(file_path) {
+        return (get_archive(file_path, kind), Some(0));
+    }
+
     let file_directory_path = file_path.parent().expect("invalid file path");
 
     // Get all images from the parent directory and filter out unsupported image types
@@ -68,18 +275,236 @@ fn get_filelist() -> (Images, Option<usize>) {
         }
     });
 
-    (image_filenames, inital_image)
+    (ImageSource::Directory(image_filenames), inital_image)
+}
+
+// A decoded, ready-to-upload frame paired with how long it stays on screen.
+// Still images decode to a single frame with a zero delay.
+type Frames = Vec<(image::RgbaImage, Duration)>;
+
+// Decode every frame of a file. Animated gif/apng/webp expose their frames via
+// the `image` crate's `AnimationDecoder`; everything else collapses to one frame.
+fn decode_frames(path: &Path, bytes: Vec<u8>) -> Result<Frames> {
+    use image::codecs::gif::GifDecoder;
+    use image::codecs::png::PngDecoder;
+    use image::codecs::webp::WebPDecoder;
+    use image::AnimationDecoder;
+    use std::io::Cursor;
+
+    let collect = |frames: image::Frames| -> Result<Frames> {
+        let mut out: Frames = Vec::new();
+        for frame in frames {
+            let frame = frame?;
+            let delay = Duration::from(frame.delay());
+            out.push((frame.into_buffer(), delay));
+        }
+        Ok(out)
+    };
+
+    let ext = path
+        .extension()
+        .and_then(OsStr::to_str)
+        .map(|s| s.to_ascii_lowercase());
+
+    let frames = match ext.as_deref() {
+        Some("gif") => collect(GifDecoder::new(Cursor::new(&bytes))?.into_frames())?,
+        Some("webp") => {
+            let decoder = WebPDecoder::new(Cursor::new(&bytes))?;
+            if decoder.has_animation() {
+                collect(decoder.into_frames())?
+            } else {
+                Vec::new()
+            }
+        }
+        Some("png" | "apng") => {
+            let decoder = PngDecoder::new(Cursor::new(&bytes))?;
+            if decoder.is_apng() {
+                collect(decoder.apng().into_frames())?
+            } else {
+                Vec::new()
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    if !frames.is_empty() {
+        return Ok(frames);
+    }
+
+    // Not animated (or a still in an animated container): decode the one frame.
+    use image::io::Reader;
+    let image = Reader::new(Cursor::new(&bytes))
+        .with_guessed_format()?
+        .decode()?
+        .to_rgba8();
+    Ok(vec![(image, Duration::ZERO)])
+}
+
+// A 5x7 bitmap font packed row-by-row; '#' is an opaque pixel. Characters not
+// listed render blank. Lowercase is folded to uppercase before lookup.
+#[rustfmt::skip]
+static FONT: &[(char, [&str; 7])] = &[
+    (' ', [".....", ".....", ".....", ".....", ".....", ".....", "....."]),
+    ('0', [".###.", "#...#", "#..##", "#.#.#", "##..#", "#...#", ".###."]),
+    ('1', ["..#..", ".##..", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('2', [".###.", "#...#", "....#", "...#.", "..#..", ".#...", "#####"]),
+    ('3', ["#####", "...#.", "..#..", "...#.", "....#", "#...#", ".###."]),
+    ('4', ["...#.", "..##.", ".#.#.", "#..#.", "#####", "...#.", "...#."]),
+    ('5', ["#####", "#....", "####.", "....#", "....#", "#...#", ".###."]),
+    ('6', ["..##.", ".#...", "#....", "####.", "#...#", "#...#", ".###."]),
+    ('7', ["#####", "....#", "...#.", "..#..", ".#...", ".#...", ".#..."]),
+    ('8', [".###.", "#...#", "#...#", ".###.", "#...#", "#...#", ".###."]),
+    ('9', [".###.", "#...#", "#...#", ".####", "....#", "...#.", ".##.."]),
+    ('A', [".###.", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"]),
+    ('B', ["####.", "#...#", "#...#", "####.", "#...#", "#...#", "####."]),
+    ('C', [".###.", "#...#", "#....", "#....", "#....", "#...#", ".###."]),
+    ('D', ["###..", "#..#.", "#...#", "#...#", "#...#", "#..#.", "###.."]),
+    ('E', ["#####", "#....", "#....", "####.", "#....", "#....", "#####"]),
+    ('F', ["#####", "#....", "#....", "####.", "#....", "#....", "#...."]),
+    ('G', [".###.", "#...#", "#....", "#.###", "#...#", "#...#", ".###."]),
+    ('H', ["#...#", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"]),
+    ('I', [".###.", "..#..", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', ["..###", "...#.", "...#.", "...#.", "#..#.", "#..#.", ".##.."]),
+    ('K', ["#...#", "#..#.", "#.#..", "##...", "#.#..", "#..#.", "#...#"]),
+    ('L', ["#....", "#....", "#....", "#....", "#....", "#....", "#####"]),
+    ('M', ["#...#", "##.##", "#.#.#", "#.#.#", "#...#", "#...#", "#...#"]),
+    ('N', ["#...#", "##..#", "#.#.#", "#.#.#", "#..##", "#...#", "#...#"]),
+    ('O', [".###.", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."]),
+    ('P', ["####.", "#...#", "#...#", "####.", "#....", "#....", "#...."]),
+    ('Q', [".###.", "#...#", "#...#", "#...#", "#.#.#", "#..#.", ".##.#"]),
+    ('R', ["####.", "#...#", "#...#", "####.", "#.#..", "#..#.", "#...#"]),
+    ('S', [".###.", "#...#", "#....", ".###.", "....#", "#...#", ".###."]),
+    ('T', ["#####", "..#..", "..#..", "..#..", "..#..", "..#..", "..#.."]),
+    ('U', ["#...#", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."]),
+    ('V', ["#...#", "#...#", "#...#", "#...#", "#...#", ".#.#.", "..#.."]),
+    ('W', ["#...#", "#...#", "#...#", "#.#.#", "#.#.#", "##.##", "#...#"]),
+    ('X', ["#...#", "#...#", ".#.#.", "..#..", ".#.#.", "#...#", "#...#"]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#..", "..#.."]),
+    ('Z', ["#####", "....#", "...#.", "..#..", ".#...", "#....", "#####"]),
+    ('/', ["....#", "....#", "...#.", "..#..", ".#...", "#....", "#...."]),
+    ('.', [".....", ".....", ".....", ".....", ".....", "..##.", "..##."]),
+    (':', [".....", "..##.", "..##.", ".....", "..##.", "..##.", "....."]),
+    ('-', [".....", ".....", ".....", "#####", ".....", ".....", "....."]),
+    ('_', [".....", ".....", ".....", ".....", ".....", ".....", "#####"]),
+    ('%', ["##..#", "##.#.", "..#..", ".#...", "#..##", "#.###", "...##"]),
+    ('(', ["..#..", ".#...", "#....", "#....", "#....", ".#...", "..#.."]),
+    (')', ["..#..", "...#.", "....#", "....#", "....#", "...#.", "..#.."]),
+];
+
+const GLYPH_W: usize = 5;
+const GLYPH_H: usize = 7;
+const GLYPH_PAD: usize = 1;
+
+// A UV rectangle into the font atlas.
+#[derive(Clone, Copy)]
+struct Rect {
+    u0: f32,
+    v0: f32,
+    u1: f32,
+    v1: f32,
+}
+
+pub const UI_VERTEX: &str = r#"#version 100
+    attribute vec2 pos;
+    attribute vec2 uv;
+    varying lowp vec2 texcoord;
+    void main() {
+        gl_Position = vec4(pos, 0, 1);
+        texcoord = uv;
+    }"#;
+
+pub const UI_FRAGMENT: &str = r#"#version 100
+    varying lowp vec2 texcoord;
+    uniform sampler2D tex;
+    void main() {
+        gl_FragColor = texture2D(tex, texcoord);
+    }"#;
+
+// Rasterize an SVG to an `RgbaImage` sized to fit the given window dimensions,
+// preserving aspect ratio so vector art stays crisp at any window size.
+fn rasterize_svg(bytes: &[u8], (sw, sh): (f32, f32)) -> Result<image::RgbaImage> {
+    use resvg::tiny_skia;
+    use resvg::usvg::{self, TreeParsing};
+
+    let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())?;
+    let size = tree.size;
+
+    let scale = (sw / size.width()).min(sh / size.height()).max(0.01);
+    let width = (size.width() * scale).ceil() as u32;
+    let height = (size.height() * scale).ceil() as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| anyhow::anyhow!("could not allocate svg pixmap"))?;
+    resvg::Tree::from_usvg(&tree).render(
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    image::RgbaImage::from_raw(width, height, pixmap.take())
+        .ok_or_else(|| anyhow::anyhow!("invalid svg pixel buffer"))
+}
+
+// Decode the modern HEIF-family formats (avif/heic) into an `RgbaImage`.
+fn decode_heif(ext: &str, bytes: Vec<u8>) -> Result<image::RgbaImage> {
+    if ext == "avif" {
+        use image::codecs::avif::AvifDecoder;
+        use image::DynamicImage;
+        use std::io::Cursor;
+
+        let decoder = AvifDecoder::new(Cursor::new(bytes))?;
+        return Ok(DynamicImage::from_decoder(decoder)?.to_rgba8());
+    }
+
+    // heic/heif go through libheif, which exposes the container's primary image
+    use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+    let lib = LibHeif::new();
+    let ctx = HeifContext::read_from_bytes(&bytes)?;
+    let handle = ctx.primary_image_handle()?;
+    let decoded = lib.decode(&handle, ColorSpace::Rgb(RgbChroma::Rgba), None)?;
+
+    let planes = decoded.planes();
+    let plane = planes
+        .interleaved
+        .ok_or_else(|| anyhow::anyhow!("heic image has no interleaved plane"))?;
+
+    // copy row by row, dropping any stride padding the decoder left in place
+    let (width, height, stride) = (plane.width, plane.height, plane.stride);
+    let mut buffer = Vec::with_capacity((width * height * 4) as usize);
+    for row in 0..height {
+        let start = row as usize * stride;
+        buffer.extend_from_slice(&plane.data[start..start + (width * 4) as usize]);
+    }
+
+    image::RgbaImage::from_raw(width, height, buffer)
+        .ok_or_else(|| anyhow::anyhow!("invalid heic pixel buffer"))
 }
 
 pub const VERTEX: &str = r#"#version 100
     attribute vec2 pos;
     uniform vec2 ratio;
+    uniform float zoom;
+    uniform vec2 pan;
     varying lowp vec2 texcoord;
     void main() {
-        gl_Position = vec4(pos * ratio, 0, 1);
+        gl_Position = vec4((pos * ratio) * zoom + pan, 0, 1);
         texcoord = vec2(max(0.0, pos.x), max(0.0, -pos.y));
     }"#;
 
+// Values passed to the vertex shader each draw; the field order must match the
+// `UniformBlockLayout` declared on the shader.
+#[repr(C)]
+struct Uniforms {
+    ratio: (f32, f32),
+    zoom: f32,
+    pan: (f32, f32),
+}
+
+// How far a single wheel notch zooms, and the range `zoom` is clamped to.
+const ZOOM_STEP: f32 = 1.1;
+const ZOOM_MIN: f32 = 0.1;
+const ZOOM_MAX: f32 = 20.0;
+
 pub const FRAGMENT: &str = r#"#version 100
     varying lowp vec2 texcoord;
     uniform sampler2D tex;
@@ -95,8 +520,138 @@ struct Stage {
     bindings: Bindings,
     pipeline: Pipeline,
     ratio: (f32, f32),
-    images: Images,
+    images: ImageSource,
     current_image_index: usize,
+
+    frames: Frames,
+    current_frame: usize,
+    frame_time: f32,
+    loop_animation: bool,
+    last_frame: Instant,
+
+    zoom: f32,
+    pan: (f32, f32),
+    mouse_down: bool,
+    last_mouse: (f32, f32),
+
+    webtoon: bool,
+    scroll_offset: f32,
+    scroll_cache: Vec<ScrollTexture>,
+
+    decode_tx: mpsc::Sender<usize>,
+    decode_rx: mpsc::Receiver<(PathBuf, Decoded)>,
+    cache: Vec<(PathBuf, image::RgbaImage)>,
+    cache_bytes: usize,
+    inflight: HashSet<PathBuf>,
+    // paths the worker declined to cache (animated png); skipped by prefetch so
+    // we don't re-read and re-scan them on every navigation. Failed reads or
+    // decodes are not recorded here and are requested again later.
+    uncacheable: HashSet<PathBuf>,
+
+    hud: bool,
+    hud_pipeline: Pipeline,
+    hud_atlas: Texture,
+    glyphs: HashMap<char, Rect>,
+    hud_bindings: Option<Bindings>,
+    hud_count: i32,
+    hud_status: String,
+
+    // raw bytes of the current SVG, kept so it can be re-rasterized on resize
+    svg_source: Option<Vec<u8>>,
+}
+
+// Pack the 5x7 bitmap font into a single RGBA atlas and return it alongside the
+// per-glyph UV rects, mirroring stevenarella's `render/atlas.rs` approach.
+fn build_font_atlas(ctx: &mut Context) -> (Texture, HashMap<char, Rect>) {
+    let cell = GLYPH_W + GLYPH_PAD;
+    let width = cell * FONT.len();
+    let height = GLYPH_H;
+
+    let mut pixels = vec![0u8; width * height * 4];
+    let mut glyphs = HashMap::new();
+
+    for (col, (ch, rows)) in FONT.iter().enumerate() {
+        let x0 = col * cell;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, pixel) in row.chars().enumerate() {
+                if pixel == '#' {
+                    let offset = ((y * width) + x0 + x) * 4;
+                    pixels[offset..offset + 4].copy_from_slice(&[255, 255, 255, 255]);
+                }
+            }
+        }
+        glyphs.insert(
+            *ch,
+            Rect {
+                u0: x0 as f32 / width as f32,
+                v0: 0.0,
+                u1: (x0 + GLYPH_W) as f32 / width as f32,
+                v1: 1.0,
+            },
+        );
+    }
+
+    let atlas = Texture::from_rgba8(ctx, width as u16, height as u16, &pixels);
+    atlas.set_filter(ctx, FilterMode::Nearest);
+    (atlas, glyphs)
+}
+
+// A decoded image kept resident for the webtoon strip, with the dimensions
+// needed to lay it out. Only a small ring around the current index is retained.
+struct ScrollTexture {
+    index: usize,
+    texture: Texture,
+    width: f32,
+    height: f32,
+}
+
+// How many images on either side of the current one the scroll ring keeps.
+const SCROLL_RING: usize = 3;
+
+// Cap on the decode-ahead cache, measured in raw RGBA pixel bytes.
+const CACHE_BYTES_CAP: usize = 256 * 1024 * 1024;
+
+// Still formats are prefetched/cached. `png` is included — the common case is a
+// plain still image — but an animated png is detected in the worker and left for
+// `decode_frames` so its playback isn't collapsed to one frame. The other
+// animated-capable containers (gif/webp) are excluded outright for the same
+// reason, as are the vector/HEIF formats with their own decode paths.
+fn is_cacheable(path: &Path) -> bool {
+    matches!(
+        path.extension()
+            .and_then(OsStr::to_str)
+            .map(|s| s.to_ascii_lowercase())
+            .as_deref(),
+        Some("jpg" | "jpeg" | "png" | "bmp" | "tif")
+    )
+}
+
+// Raw RGBA footprint of a decoded image, computed in `usize` so the product
+// can't overflow for very large images.
+fn image_bytes(image: &image::RgbaImage) -> usize {
+    image.width() as usize * image.height() as usize * 4
+}
+
+// Detect an animated PNG by scanning for an `acTL` chunk ahead of the first
+// `IDAT`; a plain PNG has no `acTL`, so it's safe to cache as a single frame.
+fn is_apng(bytes: &[u8]) -> bool {
+    const SIG: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+    if bytes.len() < 8 || bytes[..8] != SIG {
+        return false;
+    }
+    let mut pos = 8;
+    while pos + 8 <= bytes.len() {
+        let len = u32::from_be_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+            as usize;
+        match &bytes[pos + 4..pos + 8] {
+            b"acTL" => return true,
+            b"IDAT" => return false,
+            _ => {}
+        }
+        // advance past length(4) + type(4) + data(len) + crc(4)
+        pos = pos.saturating_add(12).saturating_add(len);
+    }
+    false
 }
 
 impl Stage {
@@ -113,7 +668,11 @@ impl Stage {
             ShaderMeta {
                 images: vec!["tex".to_string()],
                 uniforms: UniformBlockLayout {
-                    uniforms: vec![UniformDesc::new("ratio", UniformType::Float2)],
+                    uniforms: vec![
+                        UniformDesc::new("ratio", UniformType::Float2),
+                        UniformDesc::new("zoom", UniformType::Float1),
+                        UniformDesc::new("pan", UniformType::Float2),
+                    ],
                 },
             },
         )
@@ -135,8 +694,80 @@ impl Stage {
             shader,
         );
 
+        // screen-space text overlay: its own shader/pipeline with alpha blending
+        let ui_shader = Shader::new(
+            ctx,
+            UI_VERTEX,
+            UI_FRAGMENT,
+            ShaderMeta {
+                images: vec!["tex".to_string()],
+                uniforms: UniformBlockLayout { uniforms: vec![] },
+            },
+        )
+        .unwrap();
+
+        let hud_pipeline = Pipeline::with_params(
+            ctx,
+            &[BufferLayout::default()],
+            &[
+                VertexAttribute::new("pos", VertexFormat::Float2),
+                VertexAttribute::new("uv", VertexFormat::Float2),
+            ],
+            ui_shader,
+            PipelineParams {
+                color_blend: Some(BlendState::new(
+                    Equation::Add,
+                    BlendFactor::Value(BlendValue::SourceAlpha),
+                    BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
+                )),
+                ..Default::default()
+            },
+        );
+
+        let (hud_atlas, glyphs) = build_font_atlas(ctx);
+
         let (filelist, initial) = get_filelist();
 
+        // decode worker: owns the request channel, returns CPU-side RgbaImages.
+        // the GPU upload stays on the main thread since `Context` is not `Send`.
+        let (decode_tx, req_rx) = mpsc::channel::<usize>();
+        let (res_tx, decode_rx) = mpsc::channel::<(PathBuf, Decoded)>();
+        let mut reader = PrefetchReader::new(filelist.clone());
+        std::thread::spawn(move || {
+            use image::io::Reader;
+            use std::io::Cursor;
+            while let Ok(index) = req_rx.recv() {
+                let path = reader.source.path(index);
+                // always report back so the main thread can clear the inflight
+                // marker: `Skip` is permanent, `Failed` is retried on a later pass.
+                let outcome = match reader.read(index) {
+                    Err(_) => Decoded::Failed,
+                    Ok(bytes) => {
+                        // leave animated pngs for the main thread's frame decoder
+                        // so their playback survives; caching collapses it to one.
+                        let is_png = path
+                            .extension()
+                            .and_then(OsStr::to_str)
+                            .map(|s| s.eq_ignore_ascii_case("png"))
+                            .unwrap_or(false);
+                        if is_png && is_apng(&bytes) {
+                            Decoded::Skip
+                        } else {
+                            match Reader::new(Cursor::new(bytes))
+                                .with_guessed_format()
+                                .ok()
+                                .and_then(|r| r.decode().ok())
+                            {
+                                Some(d) => Decoded::Image(d.to_rgba8()),
+                                None => Decoded::Failed,
+                            }
+                        }
+                    }
+                };
+                let _ = res_tx.send((path, outcome));
+            }
+        });
+
         let mut stage = Stage {
             render: RENDERS,
             fullscreen: false,
@@ -146,6 +777,38 @@ impl Stage {
             ratio: (0.0, 0.0),
             images: filelist,
             current_image_index: initial.unwrap_or(0),
+
+            frames: Vec::new(),
+            current_frame: 0,
+            frame_time: 0.0,
+            loop_animation: true,
+            last_frame: Instant::now(),
+
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+            mouse_down: false,
+            last_mouse: (0.0, 0.0),
+
+            webtoon: false,
+            scroll_offset: 0.0,
+            scroll_cache: Vec::new(),
+
+            decode_tx,
+            decode_rx,
+            cache: Vec::new(),
+            cache_bytes: 0,
+            inflight: HashSet::new(),
+            uncacheable: HashSet::new(),
+
+            hud: false,
+            hud_pipeline,
+            hud_atlas,
+            glyphs,
+            hud_bindings: None,
+            hud_count: 0,
+            hud_status: String::new(),
+
+            svg_source: None,
         };
 
         stage.load_image_from_current(ctx).unwrap();
@@ -153,23 +816,50 @@ impl Stage {
         stage
     }
     fn load_image_from_current(&mut self, ctx: &mut Context) -> Result<()> {
-        // load the image
-        use image::io::Reader;
-        use std::fs::File;
-        use std::io::BufReader;
+        // load the image (possibly animated)
+        self.drain_decoded();
 
-        let path = self
-            .images
-            .get(self.current_image_index)
-            .expect("invalid image index");
-        let file = File::open(path)?;
-        let reader = Reader::new(BufReader::new(file)).with_guessed_format()?;
+        let path = self.images.path(self.current_image_index);
+        let ext = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|s| s.to_ascii_lowercase());
 
-        let image = reader.decode()?.to_rgba8();
+        // dispatch on extension: vector and HEIF-family formats take dedicated
+        // decode paths; everything else flows through the raster loader/cache
+        self.svg_source = None;
+        self.frames = match ext.as_deref() {
+            Some("svg") => {
+                let bytes = self.images.read(self.current_image_index)?;
+                let image = rasterize_svg(&bytes, ctx.screen_size())?;
+                self.svg_source = Some(bytes);
+                vec![(image, Duration::ZERO)]
+            }
+            Some(ext @ ("avif" | "heic")) => {
+                let bytes = self.images.read(self.current_image_index)?;
+                vec![(decode_heif(ext, bytes)?, Duration::ZERO)]
+            }
+            _ => match self.cache_take(&path) {
+                Some(image) => vec![(image, Duration::ZERO)],
+                None => {
+                    let bytes = self.images.read(self.current_image_index)?;
+                    decode_frames(&path, bytes)?
+                }
+            },
+        };
 
-        // pump the texture with the image
-        let texture = self.bindings.images.get_mut(0).unwrap();
-        texture.resize(ctx, image.width(), image.height(), Some(image.as_raw()));
+        // warm the cache for the neighbours we're likely to page to next
+        self.prefetch_adjacent();
+
+        self.current_frame = 0;
+        self.frame_time = 0.0;
+        self.last_frame = Instant::now();
+
+        // pump the texture with the first frame
+        self.upload_frame(ctx);
+
+        // a fresh image starts fit-to-window with no pan
+        self.reset_view();
 
         // calculate ratio of the image
         self.calculate_ratio(ctx);
@@ -177,6 +867,110 @@ impl Stage {
         Ok(())
     }
 
+    // Upload the active frame's bytes into the bound texture.
+    fn upload_frame(&mut self, ctx: &mut Context) {
+        let (image, _) = &self.frames[self.current_frame];
+        let texture = self.bindings.images.get_mut(0).unwrap();
+        texture.resize(ctx, image.width(), image.height(), Some(image.as_raw()));
+    }
+
+    // Re-rasterize the current SVG at the new window resolution so it stays
+    // sharp as the window grows. No-op for raster images.
+    fn rerasterize_svg(&mut self, ctx: &mut Context) {
+        let size = ctx.screen_size();
+        if let Some(bytes) = self.svg_source.clone() {
+            if let Ok(image) = rasterize_svg(&bytes, size) {
+                self.frames = vec![(image, Duration::ZERO)];
+                self.current_frame = 0;
+                self.upload_frame(ctx);
+            }
+        }
+    }
+
+    // True while the current file has more than one frame to play back.
+    fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    // True while an animation still has frames to advance through. A finished
+    // non-looping animation is static, so it must not keep forcing redraws.
+    fn is_playing(&self) -> bool {
+        self.is_animated() && (self.loop_animation || self.current_frame + 1 < self.frames.len())
+    }
+
+    // Toggle looping; re-enabling a finished animation resumes playback from a
+    // fresh timer instead of fast-forwarding through the paused interval.
+    fn toggle_loop(&mut self) {
+        self.loop_animation = !self.loop_animation;
+        self.frame_time = 0.0;
+        self.last_frame = Instant::now();
+        self.render = RENDERS;
+    }
+
+    // Move any finished decodes from the worker into the decode-ahead cache.
+    fn drain_decoded(&mut self) {
+        while let Ok((path, image)) = self.decode_rx.try_recv() {
+            self.inflight.remove(&path);
+            match image {
+                Decoded::Image(image) => self.cache_insert(path, image),
+                // animated png: never cacheable, so stop re-requesting it.
+                Decoded::Skip => {
+                    self.uncacheable.insert(path);
+                }
+                // transient failure: leave it out of `uncacheable` so a later
+                // navigation gets another chance to prefetch it.
+                Decoded::Failed => {}
+            }
+        }
+    }
+
+    // Insert a decoded image, evicting the oldest entries (insertion order)
+    // until the cache is back under its pixel-byte cap. The newest entry is
+    // always kept, so a single image larger than the cap stays cached alone.
+    fn cache_insert(&mut self, path: PathBuf, image: image::RgbaImage) {
+        if self.cache.iter().any(|(p, _)| *p == path) {
+            return;
+        }
+        self.cache_bytes += image_bytes(&image);
+        self.cache.push((path, image));
+        while self.cache_bytes > CACHE_BYTES_CAP && self.cache.len() > 1 {
+            let (_, evicted) = self.cache.remove(0);
+            self.cache_bytes -= image_bytes(&evicted);
+        }
+    }
+
+    // Take a cached image out of the decode-ahead cache, if present.
+    fn cache_take(&mut self, path: &Path) -> Option<image::RgbaImage> {
+        let pos = self.cache.iter().position(|(p, _)| p == path)?;
+        let (_, image) = self.cache.remove(pos);
+        self.cache_bytes -= image_bytes(&image);
+        Some(image)
+    }
+
+    // Request background decodes of the next and previous images.
+    fn prefetch_adjacent(&mut self) {
+        let len = self.images.len();
+        if len <= 1 {
+            return;
+        }
+        let current = self.current_image_index;
+        let neighbours = [(current + 1) % len, (current + len - 1) % len];
+        for index in neighbours {
+            let path = self.images.path(index);
+            if !is_cacheable(&path)
+                || self.inflight.contains(&path)
+                || self.uncacheable.contains(&path)
+                || self.cache.iter().any(|(p, _)| *p == path)
+            {
+                continue;
+            }
+            // hand the index to the worker; it reads the bytes and decodes
+            // off-thread so navigation never blocks on archive/file I/O.
+            self.inflight.insert(path);
+            let _ = self.decode_tx.send(index);
+        }
+    }
+
     fn calculate_ratio(&mut self, ctx: &mut Context) {
         // mark render
         self.render = RENDERS;
@@ -220,10 +1014,244 @@ impl Stage {
         self.load_image_from_current(ctx).unwrap();
     }
 
+    fn reset_view(&mut self) {
+        self.zoom = 1.0;
+        self.pan = (0.0, 0.0);
+        self.render = RENDERS;
+    }
+
     fn toggle_fullscreen(&mut self, ctx: &mut Context) {
         self.fullscreen = !self.fullscreen;
         ctx.set_fullscreen(self.fullscreen);
     }
+
+    // Toggle the on-screen status overlay.
+    fn toggle_hud(&mut self) {
+        self.hud = !self.hud;
+        self.render = RENDERS;
+    }
+
+    // Rebuild the HUD vertex/index buffers when the status string changes.
+    fn build_hud(&mut self, ctx: &mut Context) {
+        let path = self.images.path(self.current_image_index);
+        let name = path.file_name().and_then(OsStr::to_str).unwrap_or("");
+        let status = format!(
+            "{}  {}/{}  {:.0}%",
+            name,
+            self.current_image_index + 1,
+            self.images.len(),
+            self.zoom * 100.0,
+        );
+        if self.hud_bindings.is_some() && status == self.hud_status {
+            return;
+        }
+        self.hud_status = status.clone();
+
+        // release the previous frame's buffers before building new ones
+        if let Some(old) = self.hud_bindings.take() {
+            for vb in &old.vertex_buffers {
+                vb.delete();
+            }
+            old.index_buffer.delete();
+        }
+
+        let (sw, sh) = ctx.screen_size();
+        let glyph_h = 18.0;
+        let glyph_w = glyph_h * GLYPH_W as f32 / GLYPH_H as f32;
+        let margin = 12.0;
+        let (px, py) = (2.0 / sw, 2.0 / sh);
+
+        let mut vertices: Vec<f32> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+        let mut pen = margin;
+        for ch in status.chars() {
+            if let Some(rect) = self.glyphs.get(&ch.to_ascii_uppercase()).copied() {
+                let x0 = -1.0 + pen * px;
+                let x1 = -1.0 + (pen + glyph_w) * px;
+                let y0 = 1.0 - margin * py;
+                let y1 = 1.0 - (margin + glyph_h) * py;
+                let base = (vertices.len() / 4) as u16;
+                #[rustfmt::skip]
+                vertices.extend_from_slice(&[
+                    x0, y0, rect.u0, rect.v0,
+                    x1, y0, rect.u1, rect.v0,
+                    x1, y1, rect.u1, rect.v1,
+                    x0, y1, rect.u0, rect.v1,
+                ]);
+                indices.extend_from_slice(&[base, base + 1, base + 2, base + 2, base + 3, base]);
+            }
+            pen += glyph_w + 2.0;
+        }
+
+        self.hud_count = indices.len() as i32;
+        if indices.is_empty() {
+            return;
+        }
+
+        self.hud_bindings = Some(Bindings {
+            vertex_buffers: vec![Buffer::immutable(ctx, BufferType::VertexBuffer, &vertices)],
+            index_buffer: Buffer::immutable(ctx, BufferType::IndexBuffer, &indices),
+            images: vec![self.hud_atlas],
+        });
+    }
+
+    // Toggle the continuous vertical "webtoon" reading mode.
+    fn toggle_webtoon(&mut self, ctx: &mut Context) {
+        self.webtoon = !self.webtoon;
+        self.scroll_offset = 0.0;
+        // `Texture` is a bare handle with no `Drop`, so release the ring's GPU
+        // textures explicitly before dropping the structs that hold them.
+        for s in self.scroll_cache.drain(..) {
+            s.texture.delete();
+        }
+        if !self.webtoon {
+            // back to one-image-at-a-time: restore the single-texture path
+            self.load_image_from_current(ctx).unwrap();
+        }
+        self.render = RENDERS;
+    }
+
+    // Decode a single still frame for `index`, honouring the same extension
+    // dispatch as `load_image_from_current` so svg/avif/heic work in the strip
+    // too instead of failing through the raster reader into a 0-height gap.
+    fn decode_still(&self, ctx: &mut Context, index: usize) -> Result<image::RgbaImage> {
+        let path = self.images.path(index);
+        let bytes = self.images.read(index)?;
+        let ext = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|s| s.to_ascii_lowercase());
+        match ext.as_deref() {
+            Some("svg") => rasterize_svg(&bytes, ctx.screen_size()),
+            Some(ext @ ("avif" | "heic")) => decode_heif(ext, bytes),
+            _ => decode_frames(&path, bytes)?
+                .into_iter()
+                .next()
+                .map(|(image, _)| image)
+                .ok_or_else(|| anyhow::anyhow!("decoded no frames")),
+        }
+    }
+
+    // Fetch (decoding and uploading on a miss) the resident texture for an
+    // image, evicting entries that fall outside the ring around the current one.
+    fn scroll_texture(&mut self, ctx: &mut Context, index: usize) -> Option<usize> {
+        if let Some(slot) = self.scroll_cache.iter().position(|s| s.index == index) {
+            return Some(slot);
+        }
+
+        let image = self.decode_still(ctx, index).ok()?;
+
+        let texture = Texture::from_rgba8(ctx, image.width() as u16, image.height() as u16, image.as_raw());
+        texture.set_filter(ctx, FilterMode::Linear);
+
+        // drop textures that drifted out of the ring before adding the new one
+        let current = self.current_image_index;
+        self.scroll_cache.retain(|s| {
+            let delta = s.index.abs_diff(current);
+            if delta > SCROLL_RING {
+                s.texture.delete();
+                false
+            } else {
+                true
+            }
+        });
+
+        self.scroll_cache.push(ScrollTexture {
+            index,
+            texture,
+            width: image.width() as f32,
+            height: image.height() as f32,
+        });
+        Some(self.scroll_cache.len() - 1)
+    }
+
+    // Height of an image scaled to the full window width, in NDC units (the
+    // full screen height spans 2.0). Returns 0.0 if the image can't be loaded.
+    fn strip_height(&mut self, ctx: &mut Context, index: usize) -> f32 {
+        match self.scroll_texture(ctx, index) {
+            Some(slot) => {
+                let (sw, sh) = ctx.screen_size();
+                let s = &self.scroll_cache[slot];
+                2.0 * (sw / sh) * (s.height / s.width)
+            }
+            None => 0.0,
+        }
+    }
+
+    // Draw one image of the strip, scaled to window width and centred at `center_y`.
+    fn draw_strip_image(&mut self, ctx: &mut Context, index: usize, center_y: f32, half_height: f32) {
+        let Some(slot) = self.scroll_texture(ctx, index) else {
+            return;
+        };
+        // Bind the strip texture transiently, restoring the dedicated
+        // single-image texture afterwards so it's never orphaned.
+        let main_texture = self.bindings.images[0];
+        self.bindings.images[0] = self.scroll_cache[slot].texture;
+        ctx.apply_bindings(&self.bindings);
+        self.bindings.images[0] = main_texture;
+        ctx.apply_uniforms(&Uniforms {
+            ratio: (1.0, half_height),
+            zoom: 1.0,
+            pan: (0.0, center_y),
+        });
+        ctx.draw(0, 6, 1);
+    }
+
+    // Fold `scroll_offset` back into `current_image_index` whenever scrolling
+    // crosses an image boundary, so left/right navigation stays consistent.
+    fn normalize_scroll(&mut self, ctx: &mut Context) {
+        let len = self.images.len();
+
+        // scrolled forward past the current image: advance the anchor
+        while self.current_image_index + 1 < len {
+            let fh = self.strip_height(ctx, self.current_image_index);
+            // a decode failure reports 0.0 height; don't let it advance the
+            // anchor through every remaining image without any scrolling.
+            if fh > 0.0 && self.scroll_offset >= fh {
+                self.scroll_offset -= fh;
+                self.current_image_index += 1;
+            } else {
+                break;
+            }
+        }
+
+        // scrolled back into the previous image: retreat the anchor
+        while self.scroll_offset < 0.0 && self.current_image_index > 0 {
+            let fh = self.strip_height(ctx, self.current_image_index - 1);
+            // an undecodable previous image reports 0.0 height; don't let it
+            // run the anchor back through every earlier image in one pass.
+            if fh <= 0.0 {
+                break;
+            }
+            self.current_image_index -= 1;
+            self.scroll_offset += fh;
+        }
+
+        if self.current_image_index == 0 {
+            self.scroll_offset = self.scroll_offset.max(0.0);
+        }
+    }
+
+    // Render the stitched vertical strip around the current image.
+    fn draw_webtoon(&mut self, ctx: &mut Context) {
+        self.normalize_scroll(ctx);
+
+        let len = self.images.len();
+        let current = self.current_image_index;
+
+        // `normalize_scroll` keeps the anchor topmost (scroll_offset >= 0, unless
+        // the image above it can't be decoded), so the current image's top edge
+        // sits at or above the screen top and we only ever draw it and the
+        // images stacked below it.
+        let mut edge = 1.0 + self.scroll_offset;
+        let mut i = current;
+        while i < len && edge > -1.0 {
+            let fh = self.strip_height(ctx, i);
+            self.draw_strip_image(ctx, i, edge - fh / 2.0, fh / 2.0);
+            edge -= fh;
+            i += 1;
+        }
+    }
 }
 
 impl EventHandler for Stage {
@@ -233,6 +1261,10 @@ impl EventHandler for Stage {
             'o' => self.prev_image(ctx),
             'm' => self.toggle_flip(ctx),
             'f' => self.toggle_fullscreen(ctx),
+            'r' => self.reset_view(),
+            'w' => self.toggle_webtoon(ctx),
+            'h' => self.toggle_hud(),
+            'l' => self.toggle_loop(),
 
             'q' => std::process::exit(0),
 
@@ -247,6 +1279,15 @@ impl EventHandler for Stage {
             Left => self.prev_image(ctx),
             Space => self.random_image(ctx),
 
+            Up if self.webtoon => {
+                self.scroll_offset -= 0.15;
+                self.render = RENDERS;
+            }
+            Down if self.webtoon => {
+                self.scroll_offset += 0.15;
+                self.render = RENDERS;
+            }
+
             Escape => std::process::exit(0),
 
             _ => {}
@@ -254,18 +1295,125 @@ impl EventHandler for Stage {
     }
 
     fn resize_event(&mut self, ctx: &mut Context, _: f32, _: f32) {
+        self.rerasterize_svg(ctx);
         self.calculate_ratio(ctx);
     }
 
-    fn update(&mut self, _ctx: &mut Context) {}
+    fn mouse_wheel_event(&mut self, ctx: &mut Context, _x: f32, y: f32) {
+        if y == 0.0 {
+            return;
+        }
+
+        // in webtoon mode the wheel scrolls the strip instead of zooming
+        if self.webtoon {
+            // wheel toward later images (y negative) scrolls the strip forward
+            self.scroll_offset -= y.signum() * 0.15;
+            self.render = RENDERS;
+            return;
+        }
+
+        // normalized device coords of the cursor, so the point under it stays put
+        let (sw, sh) = ctx.screen_size();
+        let (mx, my) = self.last_mouse;
+        let cursor = (mx / sw * 2.0 - 1.0, 1.0 - my / sh * 2.0);
+
+        let factor = if y > 0.0 { ZOOM_STEP } else { 1.0 / ZOOM_STEP };
+        let new_zoom = (self.zoom * factor).clamp(ZOOM_MIN, ZOOM_MAX);
+        let scale = new_zoom / self.zoom;
+
+        self.pan.0 = cursor.0 - (cursor.0 - self.pan.0) * scale;
+        self.pan.1 = cursor.1 - (cursor.1 - self.pan.1) * scale;
+        self.zoom = new_zoom;
+
+        self.render = RENDERS;
+    }
+
+    fn mouse_button_down_event(&mut self, _ctx: &mut Context, button: MouseButton, x: f32, y: f32) {
+        if let MouseButton::Left = button {
+            self.mouse_down = true;
+            self.last_mouse = (x, y);
+        }
+    }
+
+    fn mouse_button_up_event(&mut self, _ctx: &mut Context, button: MouseButton, _x: f32, _y: f32) {
+        if let MouseButton::Left = button {
+            self.mouse_down = false;
+        }
+    }
+
+    fn mouse_motion_event(&mut self, ctx: &mut Context, x: f32, y: f32) {
+        if self.mouse_down {
+            // translate pan by the cursor delta expressed in device coords
+            let (sw, sh) = ctx.screen_size();
+            self.pan.0 += (x - self.last_mouse.0) / sw * 2.0;
+            self.pan.1 -= (y - self.last_mouse.1) / sh * 2.0;
+            self.render = RENDERS;
+        }
+        self.last_mouse = (x, y);
+    }
+
+    fn update(&mut self, ctx: &mut Context) {
+        if !self.is_animated() {
+            return;
+        }
+
+        // advance playback by the wall-clock delta since the last update
+        let now = Instant::now();
+        self.frame_time += now.duration_since(self.last_frame).as_secs_f32();
+        self.last_frame = now;
+
+        loop {
+            let delay = self.frames[self.current_frame].1.as_secs_f32();
+            if delay <= 0.0 || self.frame_time < delay {
+                break;
+            }
+            self.frame_time -= delay;
+
+            let next = self.current_frame + 1;
+            if next >= self.frames.len() {
+                if !self.loop_animation {
+                    self.current_frame = self.frames.len() - 1;
+                    break;
+                }
+                self.current_frame = 0;
+            } else {
+                self.current_frame = next;
+            }
+            self.upload_frame(ctx);
+        }
+    }
 
     fn draw(&mut self, ctx: &mut Context) {
+        // keep rendering every frame while an animation is still advancing
+        if self.is_playing() {
+            self.render = RENDERS;
+        }
+
         if self.render > 0 {
             ctx.begin_default_pass(PassAction::clear_color(0.0, 0.0, 0.0, 0.0));
             ctx.apply_pipeline(&self.pipeline);
-            ctx.apply_bindings(&self.bindings);
-            ctx.apply_uniforms(&[self.ratio]);
-            ctx.draw(0, 6, 1);
+
+            if self.webtoon {
+                self.draw_webtoon(ctx);
+            } else {
+                ctx.apply_bindings(&self.bindings);
+                ctx.apply_uniforms(&Uniforms {
+                    ratio: self.ratio,
+                    zoom: self.zoom,
+                    pan: self.pan,
+                });
+                ctx.draw(0, 6, 1);
+            }
+
+            // status overlay drawn on top of the image
+            if self.hud {
+                self.build_hud(ctx);
+                if let Some(bindings) = &self.hud_bindings {
+                    ctx.apply_pipeline(&self.hud_pipeline);
+                    ctx.apply_bindings(bindings);
+                    ctx.draw(0, self.hud_count, 1);
+                }
+            }
             ctx.end_render_pass();
 
             self.render -= 1;